@@ -0,0 +1,219 @@
+//! Converts between `SheetData` and RFC 4180 CSV text.
+
+use crate::parser::{CellData, CellValue, RowData, SheetData};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvConfig {
+    pub delimiter: u8,
+    pub has_headers: bool,
+    pub quote: u8,
+}
+
+impl Default for CsvConfig {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+            quote: b'"',
+        }
+    }
+}
+
+pub struct CsvReader {
+    file_path: String,
+}
+
+impl CsvReader {
+    pub fn new(file_path: impl Into<String>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+
+    /// Read the CSV file into a `SheetData`, inferring each cell's `CellValue`
+    /// type (tries `Int`, then `Float`, then `Bool`, else `String`).
+    pub fn read(&self, config: &CsvConfig) -> Result<SheetData, Box<dyn Error>> {
+        let content = std::fs::read_to_string(&self.file_path)?;
+        let mut records = parse_records(&content, config.delimiter, config.quote);
+
+        if records.is_empty() {
+            return Ok(SheetData {
+                name: "Sheet1".to_string(),
+                column_names: Vec::new(),
+                rows: Vec::new(),
+                total_rows: 0,
+                total_columns: 0,
+            });
+        }
+
+        let headers: Vec<String> = if config.has_headers {
+            records.remove(0)
+        } else {
+            (0..records[0].len())
+                .map(|i| format!("Column{}", i + 1))
+                .collect()
+        };
+
+        let rows: Vec<RowData> = records
+            .into_iter()
+            .enumerate()
+            .map(|(row_index, fields)| {
+                let cells = fields
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(col_index, field)| {
+                        headers.get(col_index).map(|header| CellData {
+                            column_name: header.clone(),
+                            value: infer_cell_value(&field),
+                        })
+                    })
+                    .collect();
+
+                RowData {
+                    cells,
+                    row_index: row_index as u32 + 1,
+                }
+            })
+            .collect();
+
+        let total_rows = rows.len() as u32;
+        let total_columns = headers.len() as u32;
+
+        Ok(SheetData {
+            name: "Sheet1".to_string(),
+            column_names: headers,
+            rows,
+            total_rows,
+            total_columns,
+        })
+    }
+}
+
+pub struct CsvWriter {
+    file_path: String,
+}
+
+impl CsvWriter {
+    pub fn new(file_path: impl Into<String>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+
+    /// Write a `SheetData` out as CSV, quoting fields that contain the
+    /// delimiter, the quote character, or a newline (doubling embedded quotes).
+    pub fn write(&self, sheet: &SheetData, config: &CsvConfig) -> Result<(), Box<dyn Error>> {
+        let mut output = String::new();
+
+        if !sheet.column_names.is_empty() {
+            output.push_str(&encode_record(&sheet.column_names, config));
+        }
+
+        for row in &sheet.rows {
+            let fields: Vec<String> = row
+                .cells
+                .iter()
+                .map(|cell| cell.value.as_display_string())
+                .collect();
+            output.push_str(&encode_record(&fields, config));
+        }
+
+        std::fs::write(&self.file_path, output)?;
+        Ok(())
+    }
+}
+
+fn infer_cell_value(field: &str) -> CellValue {
+    if let Ok(i) = field.parse::<i64>() {
+        CellValue::Int(i)
+    } else if let Ok(f) = field.parse::<f64>() {
+        CellValue::Float(f)
+    } else if let Ok(b) = field.parse::<bool>() {
+        CellValue::Bool(b)
+    } else {
+        CellValue::String(field.to_string())
+    }
+}
+
+fn encode_record(fields: &[String], config: &CsvConfig) -> String {
+    let delimiter = config.delimiter as char;
+    let quote = config.quote as char;
+
+    let encoded: Vec<String> = fields
+        .iter()
+        .map(|field| encode_field(field, delimiter, quote))
+        .collect();
+
+    let mut line = encoded.join(&delimiter.to_string());
+    line.push('\n');
+    line
+}
+
+fn encode_field(field: &str, delimiter: char, quote: char) -> String {
+    let needs_quoting =
+        field.contains(delimiter) || field.contains(quote) || field.contains(['\n', '\r']);
+
+    if needs_quoting {
+        let doubled_quote = format!("{quote}{quote}");
+        let escaped = field.replace(quote, &doubled_quote);
+        format!("{quote}{escaped}{quote}")
+    } else {
+        field.to_string()
+    }
+}
+
+/// Minimal RFC 4180 parser: handles quoted fields, embedded delimiters,
+/// newlines, and doubled-quote escaping.
+fn parse_records(content: &str, delimiter: u8, quote: u8) -> Vec<Vec<String>> {
+    let delimiter = delimiter as char;
+    let quote = quote as char;
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut field_was_quoted = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == quote {
+                if chars.peek() == Some(&quote) {
+                    field.push(quote);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == quote && field.is_empty() && !field_was_quoted {
+            in_quotes = true;
+            field_was_quoted = true;
+        } else if c == delimiter {
+            record.push(std::mem::take(&mut field));
+            field_was_quoted = false;
+        } else if c == '\r' {
+            // paired with the '\n' that follows
+        } else if c == '\n' {
+            record.push(std::mem::take(&mut field));
+            field_was_quoted = false;
+            records.push(std::mem::take(&mut record));
+        } else if field_was_quoted {
+            // Stray characters after a closing quote aren't part of the
+            // field per RFC 4180; drop them instead of silently merging
+            // them into the quoted value.
+        } else {
+            field.push(c);
+        }
+    }
+
+    if !field.is_empty() || field_was_quoted || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
@@ -0,0 +1,161 @@
+//! Serde-style deserialization of `RowData` into user-defined structs, in
+//! the spirit of calamine's `RangeDeserializer`: each row is presented to
+//! serde as a map of `column_name -> CellValue` and the target type drives
+//! field extraction by name.
+
+use crate::parser::{CellValue, RowData};
+use serde::de::{
+    self, value::StrDeserializer, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess,
+    Visitor,
+};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RowDeserializeError {
+    HeaderNotFound(String),
+    CellError { column: String, message: String },
+    Custom(String),
+}
+
+impl fmt::Display for RowDeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowDeserializeError::HeaderNotFound(header) => {
+                write!(f, "header not found: {header}")
+            }
+            RowDeserializeError::CellError { column, message } => {
+                write!(f, "column \"{column}\": {message}")
+            }
+            RowDeserializeError::Custom(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for RowDeserializeError {}
+
+impl de::Error for RowDeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        RowDeserializeError::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        RowDeserializeError::HeaderNotFound(field.to_string())
+    }
+}
+
+/// Deserializes a single `RowData` as a serde map keyed by column name.
+pub struct RowDeserializer<'a>(pub &'a RowData);
+
+impl<'de, 'a> Deserializer<'de> for RowDeserializer<'a> {
+    type Error = RowDeserializeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(RowMapAccess {
+            cells: self.0.cells.iter(),
+            current_column: None,
+            value: None,
+        })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum
+        identifier ignored_any
+    }
+}
+
+struct RowMapAccess<'a> {
+    cells: std::slice::Iter<'a, crate::parser::CellData>,
+    current_column: Option<String>,
+    value: Option<&'a CellValue>,
+}
+
+impl<'de, 'a> MapAccess<'de> for RowMapAccess<'a> {
+    type Error = RowDeserializeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.cells.next() {
+            Some(cell) => {
+                self.current_column = Some(cell.column_name.clone());
+                self.value = Some(&cell.value);
+                let key_de: StrDeserializer<Self::Error> = cell.column_name.as_str().into_deserializer();
+                seed.deserialize(key_de).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let column = self.current_column.take().unwrap_or_default();
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| RowDeserializeError::Custom("value requested before key".to_string()))?;
+
+        seed.deserialize(CellValueDeserializer(value))
+            .map_err(|err| match err {
+                RowDeserializeError::Custom(message) => {
+                    RowDeserializeError::CellError { column, message }
+                }
+                other => other,
+            })
+    }
+}
+
+struct CellValueDeserializer<'a>(&'a CellValue);
+
+impl<'de, 'a> Deserializer<'de> for CellValueDeserializer<'a> {
+    type Error = RowDeserializeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            CellValue::Int(i) => visitor.visit_i64(*i),
+            CellValue::Float(f) => visitor.visit_f64(*f),
+            CellValue::Bool(b) => visitor.visit_bool(*b),
+            CellValue::DateTime(s) | CellValue::String(s) | CellValue::Error(s) => {
+                visitor.visit_str(s)
+            }
+            CellValue::Empty => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            CellValue::Empty => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+        identifier ignored_any
+    }
+}
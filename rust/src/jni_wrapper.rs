@@ -1,8 +1,10 @@
+use crate::csv::{CsvConfig, CsvReader, CsvWriter};
 use crate::messagepack_converter::MessagePackConverter;
 use crate::parser::ExcelParser;
+use crate::tables::{TableFormat, TableRenderer};
 use crate::writer::{ExcelWriter, WriteConfig};
-use jni::objects::{JClass, JString, JByteArray};
-use jni::sys::{jbyteArray, jboolean};
+use jni::objects::{JClass, JString, JByteArray, JIntArray};
+use jni::sys::{jbyteArray, jboolean, jint, jintArray};
 use jni::JNIEnv;
 
 // Helper function to convert bytes to Java byte array
@@ -131,6 +133,25 @@ fn java_array_to_bytes(env: &mut JNIEnv, array: jbyteArray) -> Option<Vec<u8>> {
     }
 }
 
+// Helper function to convert a Java int array (or a null/empty one) into an
+// optional column-index list for `ExcelParser::read_range`'s `columns` param.
+fn java_int_array_to_indices(env: &mut JNIEnv, array: jintArray) -> Option<Vec<usize>> {
+    if array.is_null() {
+        return None;
+    }
+
+    let j_array: JIntArray = unsafe { JIntArray::from_raw(array) };
+    let len = env.get_array_length(&j_array).ok()?;
+    if len == 0 {
+        return None;
+    }
+
+    let mut values = vec![0i32; len as usize];
+    env.get_int_array_region(&j_array, 0, &mut values).ok()?;
+
+    Some(values.iter().map(|&v| v.max(0) as usize).collect())
+}
+
 #[no_mangle]
 pub extern "system" fn Java_io_github_mangjoo_ExcelParser_writeExcel<'local>(
     mut env: JNIEnv<'local>,
@@ -194,11 +215,186 @@ pub extern "system" fn Java_io_github_mangjoo_ExcelParser_writeMultipleSheets<'l
         Ok(c) => c,
         Err(_) => return 0, // false
     };
-    
+
     let writer = ExcelWriter::new(path);
-    
+
     match writer.write_multiple_sheets(configs) {
         Ok(_) => 1, // true
         Err(_) => 0, // false
     }
 }
+
+#[no_mangle]
+pub extern "system" fn Java_io_github_mangjoo_ExcelParser_readCsv<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    file_path: JString<'local>,
+    config_bytes: jbyteArray,
+) -> jbyteArray {
+    let path = match get_string_from_java(&mut env, &file_path) {
+        Some(p) => p,
+        None => return std::ptr::null_mut(),
+    };
+
+    let bytes = match java_array_to_bytes(&mut env, config_bytes) {
+        Some(b) => b,
+        None => return std::ptr::null_mut(),
+    };
+
+    let config: CsvConfig = match MessagePackConverter::csv_config_from_bytes(&bytes) {
+        Ok(c) => c,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let reader = CsvReader::new(path);
+
+    match reader.read(&config) {
+        Ok(sheet) => match MessagePackConverter::sheet_to_bytes(&sheet) {
+            Ok(bytes) => bytes_to_java_array(&mut env, bytes),
+            Err(_) => std::ptr::null_mut(),
+        },
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_github_mangjoo_ExcelParser_writeCsv<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    file_path: JString<'local>,
+    sheet_bytes: jbyteArray,
+    config_bytes: jbyteArray,
+) -> jboolean {
+    let path = match get_string_from_java(&mut env, &file_path) {
+        Some(p) => p,
+        None => return 0, // false
+    };
+
+    let sheet_bytes = match java_array_to_bytes(&mut env, sheet_bytes) {
+        Some(b) => b,
+        None => return 0, // false
+    };
+
+    let config_bytes = match java_array_to_bytes(&mut env, config_bytes) {
+        Some(b) => b,
+        None => return 0, // false
+    };
+
+    let sheet = match MessagePackConverter::sheet_from_bytes(&sheet_bytes) {
+        Ok(s) => s,
+        Err(_) => return 0, // false
+    };
+
+    let config: CsvConfig = match MessagePackConverter::csv_config_from_bytes(&config_bytes) {
+        Ok(c) => c,
+        Err(_) => return 0, // false
+    };
+
+    let writer = CsvWriter::new(path);
+
+    match writer.write(&sheet, &config) {
+        Ok(_) => 1, // true
+        Err(_) => 0, // false
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_github_mangjoo_ExcelParser_readRange<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    file_path: JString<'local>,
+    sheet_name: JString<'local>,
+    start_row: jint,
+    end_row: jint,
+    columns: jintArray,
+) -> jbyteArray {
+    let path = match get_string_from_java(&mut env, &file_path) {
+        Some(p) => p,
+        None => return std::ptr::null_mut(),
+    };
+
+    let sheet_name = match get_string_from_java(&mut env, &sheet_name) {
+        Some(s) => s,
+        None => return std::ptr::null_mut(),
+    };
+
+    // Java passes -1 (or any non-positive value) for "read to the last row".
+    let end_row = if end_row <= 0 { u32::MAX } else { end_row as u32 };
+    let columns = java_int_array_to_indices(&mut env, columns);
+
+    let parser = ExcelParser::new(path);
+
+    match parser.read_range(&sheet_name, start_row.max(0) as u32, end_row, columns.as_deref()) {
+        Ok(sheet) => match MessagePackConverter::sheet_to_bytes(&sheet) {
+            Ok(bytes) => bytes_to_java_array(&mut env, bytes),
+            Err(_) => std::ptr::null_mut(),
+        },
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_github_mangjoo_ExcelParser_readRowsPage<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    file_path: JString<'local>,
+    sheet_name: JString<'local>,
+    offset: jint,
+    limit: jint,
+) -> jbyteArray {
+    let path = match get_string_from_java(&mut env, &file_path) {
+        Some(p) => p,
+        None => return std::ptr::null_mut(),
+    };
+
+    let sheet_name = match get_string_from_java(&mut env, &sheet_name) {
+        Some(s) => s,
+        None => return std::ptr::null_mut(),
+    };
+
+    let parser = ExcelParser::new(path);
+
+    match parser.read_rows_page(&sheet_name, offset.max(0) as usize, limit.max(0) as usize) {
+        Ok(rows) => match MessagePackConverter::rows_to_bytes(&rows) {
+            Ok(bytes) => bytes_to_java_array(&mut env, bytes),
+            Err(_) => std::ptr::null_mut(),
+        },
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_io_github_mangjoo_ExcelParser_writeTable<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    file_path: JString<'local>,
+    config_bytes: jbyteArray,
+    format: jint,
+) -> jboolean {
+    let path = match get_string_from_java(&mut env, &file_path) {
+        Some(p) => p,
+        None => return 0, // false
+    };
+
+    let bytes = match java_array_to_bytes(&mut env, config_bytes) {
+        Some(b) => b,
+        None => return 0, // false
+    };
+
+    let config = match MessagePackConverter::table_config_from_bytes(&bytes) {
+        Ok(c) => c,
+        Err(_) => return 0, // false
+    };
+
+    let format = match format {
+        0 => TableFormat::Markdown,
+        _ => TableFormat::AsciiDoc,
+    };
+
+    let rendered = TableRenderer::render(&config.sheet, &config.headers, format);
+
+    match std::fs::write(&path, rendered) {
+        Ok(_) => 1, // true
+        Err(_) => 0, // false
+    }
+}
@@ -1,11 +1,15 @@
+pub mod csv;
+pub mod de;
 pub mod jni_wrapper;
 pub mod messagepack_converter;
 pub mod parser;
+pub mod tables;
+pub mod writer;
 
 #[cfg(test)]
 mod tests {
     use super::messagepack_converter::MessagePackConverter;
-    use super::parser::ExcelParser;
+    use super::parser::{CellValue, ExcelParser};
 
     #[test]
     fn test_excel_parsing() {
@@ -47,12 +51,39 @@ mod tests {
 
         // Verify key-value mapping
         assert_eq!(first_row.cells[0].column_name, "ID");
-        assert_eq!(first_row.cells[0].value, "1");
+        // calamine reads every numeric XLSX cell as a float.
+        assert_eq!(first_row.cells[0].value, CellValue::Float(1.0));
         assert_eq!(first_row.cells[1].column_name, "Name");
-        assert_eq!(first_row.cells[1].value, "AliceAA");
+        assert_eq!(first_row.cells[1].value, CellValue::String("AliceAA".to_string()));
         assert_eq!(first_row.cells[2].column_name, "Age");
-        assert_eq!(first_row.cells[2].value, "35");
+        assert_eq!(first_row.cells[2].value, CellValue::Float(35.0));
 
         println!("✅ Key-value mapping verified");
     }
+
+    #[test]
+    fn test_cell_value_round_trip_through_messagepack() {
+        let parser = ExcelParser::new("example.xlsx");
+        let data = parser.read_data().expect("Failed to read Excel");
+
+        let bytes = MessagePackConverter::to_bytes(&data).expect("Failed to serialize");
+        let deserialized = MessagePackConverter::from_bytes(&bytes).expect("Failed to deserialize");
+
+        let original_cells = &data.sheets[0].rows[0].cells;
+        let round_tripped_cells = &deserialized.sheets[0].rows[0].cells;
+
+        assert_eq!(original_cells.len(), round_tripped_cells.len());
+        for (original, round_tripped) in original_cells.iter().zip(round_tripped_cells) {
+            assert_eq!(original.value, round_tripped.value);
+        }
+    }
+
+    #[test]
+    fn test_cell_value_as_display_string() {
+        assert_eq!(CellValue::Int(42).as_display_string(), "42");
+        assert_eq!(CellValue::Float(3.5).as_display_string(), "3.5");
+        assert_eq!(CellValue::Bool(true).as_display_string(), "true");
+        assert_eq!(CellValue::String("hi".to_string()).as_display_string(), "hi");
+        assert_eq!(CellValue::Empty.as_display_string(), "");
+    }
 }
@@ -1,4 +1,6 @@
-use crate::parser::{ExcelData, SheetData};
+use crate::csv::CsvConfig;
+use crate::parser::{ExcelData, RowData, SheetData};
+use crate::tables::TableWriteConfig;
 use crate::writer::WriteConfig;
 
 pub struct MessagePackConverter;
@@ -27,7 +29,11 @@ impl MessagePackConverter {
     pub fn headers_from_bytes(bytes: &[u8]) -> Result<Vec<String>, rmp_serde::decode::Error> {
         rmp_serde::from_slice(bytes)
     }
-    
+
+    pub fn rows_to_bytes(rows: &[RowData]) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(rows)
+    }
+
     pub fn write_config_to_bytes(config: &WriteConfig) -> Result<Vec<u8>, rmp_serde::encode::Error> {
         rmp_serde::to_vec(config)
     }
@@ -43,4 +49,16 @@ impl MessagePackConverter {
     pub fn write_configs_from_bytes(bytes: &[u8]) -> Result<Vec<WriteConfig>, rmp_serde::decode::Error> {
         rmp_serde::from_slice(bytes)
     }
+
+    pub fn csv_config_to_bytes(config: &CsvConfig) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec(config)
+    }
+
+    pub fn csv_config_from_bytes(bytes: &[u8]) -> Result<CsvConfig, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+
+    pub fn table_config_from_bytes(bytes: &[u8]) -> Result<TableWriteConfig, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
 }
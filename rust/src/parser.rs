@@ -1,7 +1,52 @@
-use calamine::{open_workbook, Reader, Xlsx};
+use calamine::{open_workbook, DataType, Range, Reader, Xlsx};
+use chrono::{NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
+/// Reads the first row of a range as header names. Shared by every entry
+/// point that needs headers, so they stay in lockstep.
+fn extract_headers(range: &Range<DataType>) -> Vec<String> {
+    range
+        .rows()
+        .next()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Picks out the items at `indices`, or all of them when `indices` is `None`.
+/// Used to project both header names and row cells onto a `columns` selection.
+fn select_indices<T: Clone>(items: &[T], indices: Option<&[usize]>) -> Vec<T> {
+    match indices {
+        Some(indices) => indices.iter().filter_map(|&i| items.get(i).cloned()).collect(),
+        None => items.to_vec(),
+    }
+}
+
+/// Excel (and calamine) store date/time cells as a float serial number of
+/// days since the 1900 epoch, with the well-known 1900 leap-year bug baked
+/// in. Converts that serial into an ISO-8601 string, or just a time-of-day
+/// (`HH:MM:SS`) when the serial represents a bare time (`serial < 1`).
+fn excel_serial_to_iso8601(serial: f64) -> String {
+    if serial < 1.0 {
+        let day_seconds = (serial * 86400.0).round().rem_euclid(86400.0) as u32;
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(day_seconds, 0)
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        return time.format("%H:%M:%S").to_string();
+    }
+
+    let unix_days = serial - 25569.0;
+    // Rounded to whole milliseconds first, so a serial such as 13:45 that the
+    // float lands a hair below doesn't floor to 13:44:59.
+    let unix_millis = (unix_days * 86_400_000.0).round() as i64;
+    let whole_secs = unix_millis.div_euclid(1000);
+    let sub_second_nanos = (unix_millis.rem_euclid(1000) * 1_000_000) as u32;
+
+    match NaiveDateTime::from_timestamp_opt(whole_secs, sub_second_nanos) {
+        Some(naive) => naive.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        None => serial.to_string(),
+    }
+}
+
 pub struct ExcelParser {
     file_path: String,
 }
@@ -14,20 +59,7 @@ impl ExcelParser {
     }
 
     pub fn read_headers(&self) -> Result<Vec<String>, Box<dyn Error>> {
-        let mut workbook: Xlsx<_> = open_workbook(&self.file_path)?;
-        let sheet_names = workbook.sheet_names().to_vec();
-
-        if sheet_names.is_empty() {
-            return Err("No worksheets found".into());
-        }
-
-        let range = workbook.worksheet_range(&sheet_names[0])?;
-        let headers: Vec<String> = range
-            .rows()
-            .next()
-            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
-            .unwrap_or_default();
-
+        let (headers, _range) = self.load_range(None)?;
         Ok(headers)
     }
 
@@ -43,6 +75,150 @@ impl ExcelParser {
         Ok(ExcelData { sheets })
     }
 
+    /// Deserialize a sheet's rows into `T`, matching fields by header name.
+    /// The first row is treated as headers, exactly like `read_data`.
+    pub fn deserialize_sheet<T: serde::de::DeserializeOwned>(
+        &self,
+        sheet_name: &str,
+    ) -> Result<Vec<T>, Box<dyn Error>> {
+        let data = self.read_data()?;
+        let sheet = data
+            .sheets
+            .into_iter()
+            .find(|sheet| sheet.name == sheet_name)
+            .ok_or_else(|| format!("Sheet not found: {sheet_name}"))?;
+
+        sheet
+            .rows
+            .iter()
+            .map(|row| {
+                T::deserialize(crate::de::RowDeserializer(row))
+                    .map_err(|err| Box::new(err) as Box<dyn Error>)
+            })
+            .collect()
+    }
+
+    /// Read only rows `start_row..=end_row` (1-based, matching `RowData::row_index`)
+    /// and, if given, only the columns at `columns`, instead of materializing the
+    /// whole sheet like `read_data` does.
+    pub fn read_range(
+        &self,
+        sheet_name: &str,
+        start_row: u32,
+        end_row: u32,
+        columns: Option<&[usize]>,
+    ) -> Result<SheetData, Box<dyn Error>> {
+        let (headers, range) = self.load_range(Some(sheet_name))?;
+        let headers = select_indices(&headers, columns);
+
+        let mut rows = Vec::new();
+        for (row_index, row) in range.rows().skip(1).enumerate() {
+            let row_number = row_index as u32 + 1;
+            if row_number > end_row {
+                break;
+            }
+            if row_number < start_row {
+                continue;
+            }
+
+            let selected = select_indices(row, columns);
+            let cells = selected
+                .iter()
+                .enumerate()
+                .filter_map(|(col_index, cell)| {
+                    headers.get(col_index).map(|header| CellData {
+                        column_name: header.clone(),
+                        value: CellValue::from_calamine(cell),
+                    })
+                })
+                .collect();
+
+            rows.push(RowData {
+                cells,
+                row_index: row_number,
+            });
+        }
+
+        let total_rows = rows.len() as u32;
+        let total_columns = headers.len() as u32;
+
+        Ok(SheetData {
+            name: sheet_name.to_string(),
+            column_names: headers,
+            rows,
+            total_rows,
+            total_columns,
+        })
+    }
+
+    /// Lazily converts a sheet's data rows to `RowData`, one at a time, so a
+    /// caller can stop early (see `read_rows_page`) without paying for the
+    /// conversion of rows it never looks at. The `Range` itself is already
+    /// fully parsed by calamine (it doesn't stream from disk), but this
+    /// avoids any further up-front clone of the sheet: `range` is moved into
+    /// the iterator and indexed on demand as the caller advances it.
+    pub fn stream_rows(
+        &self,
+        sheet_name: &str,
+    ) -> Result<impl Iterator<Item = Result<RowData, Box<dyn Error>>>, Box<dyn Error>> {
+        let (headers, range) = self.load_range(Some(sheet_name))?;
+        let total_rows = range.height();
+        let total_columns = range.width();
+
+        Ok((1..total_rows).map(move |row_index| {
+            let cells = (0..total_columns)
+                .filter_map(|col_index| {
+                    let cell = range.get((row_index, col_index))?;
+                    headers.get(col_index).map(|header| CellData {
+                        column_name: header.clone(),
+                        value: CellValue::from_calamine(cell),
+                    })
+                })
+                .collect();
+
+            Ok(RowData {
+                cells,
+                row_index: row_index as u32,
+            })
+        }))
+    }
+
+    /// Page through a sheet's rows via `stream_rows`, e.g. for Java callers
+    /// reading millions of rows in bounded-size chunks.
+    pub fn read_rows_page(
+        &self,
+        sheet_name: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<RowData>, Box<dyn Error>> {
+        self.stream_rows(sheet_name)?.skip(offset).take(limit).collect()
+    }
+
+    /// Opens the workbook and returns the sheet's headers alongside its full
+    /// `Range`, so header extraction and row iteration share one open.
+    /// `sheet_name: None` resolves to the workbook's first sheet, within the
+    /// same open, so callers that only want headers (`read_headers`) don't
+    /// have to open the workbook a second time to discover the sheet name.
+    fn load_range(
+        &self,
+        sheet_name: Option<&str>,
+    ) -> Result<(Vec<String>, Range<DataType>), Box<dyn Error>> {
+        let mut workbook: Xlsx<_> = open_workbook(&self.file_path)?;
+
+        let resolved_name = match sheet_name {
+            Some(name) => name.to_string(),
+            None => workbook
+                .sheet_names()
+                .first()
+                .cloned()
+                .ok_or("No worksheets found")?,
+        };
+
+        let range = workbook.worksheet_range(&resolved_name)?;
+        let headers = extract_headers(&range);
+        Ok((headers, range))
+    }
+
     fn read_sheet(
         &self,
         workbook: &mut Xlsx<std::io::BufReader<std::fs::File>>,
@@ -51,10 +227,8 @@ impl ExcelParser {
         let range = workbook.worksheet_range(sheet_name)?;
         let mut rows_iter = range.rows();
 
-        let headers: Vec<String> = rows_iter
-            .next()
-            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
-            .unwrap_or_default();
+        let headers: Vec<String> = extract_headers(&range);
+        rows_iter.next();
 
         let mut rows = Vec::new();
         for (row_index, row) in rows_iter.enumerate() {
@@ -64,7 +238,7 @@ impl ExcelParser {
                 .filter_map(|(col_index, cell)| {
                     headers.get(col_index).map(|header| CellData {
                         column_name: header.clone(),
-                        value: cell.to_string(),
+                        value: CellValue::from_calamine(cell),
                     })
                 })
                 .collect();
@@ -88,10 +262,64 @@ impl ExcelParser {
     }
 }
 
+/// A single cell's value, preserving the native calamine `DataType` instead
+/// of collapsing everything to a string.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum CellValue {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    DateTime(String),
+    String(String),
+    Error(String),
+    Empty,
+}
+
+impl CellValue {
+    /// Converts a calamine cell to a `CellValue`, normalizing date/time
+    /// cells to ISO-8601 via `excel_serial_to_iso8601`.
+    ///
+    /// Date detection relies entirely on calamine having already tagged the
+    /// cell as `DataType::DateTime` while parsing the sheet's number format.
+    /// This is intentional: we only see the resolved `DataType` here, not the
+    /// cell's style/number-format XML, so there is no number-format check of
+    /// our own to fall back on. A date-formatted cell that calamine surfaces
+    /// as `DataType::Float` instead (for example, due to a number format it
+    /// doesn't recognize as a date) will come through as `CellValue::Float`
+    /// and will not be normalized.
+    fn from_calamine(cell: &DataType) -> Self {
+        match cell {
+            DataType::Int(i) => CellValue::Int(*i),
+            DataType::Float(f) => CellValue::Float(*f),
+            DataType::Bool(b) => CellValue::Bool(*b),
+            DataType::DateTime(serial) => CellValue::DateTime(excel_serial_to_iso8601(*serial)),
+            DataType::DateTimeIso(iso) => CellValue::DateTime(iso.clone()),
+            DataType::String(s) => CellValue::String(s.clone()),
+            DataType::Error(e) => CellValue::Error(e.to_string()),
+            DataType::Empty => CellValue::Empty,
+            other => CellValue::String(other.to_string()),
+        }
+    }
+
+    /// Render the value as a plain string, for callers that don't care about type fidelity.
+    pub fn as_display_string(&self) -> String {
+        match self {
+            CellValue::Int(i) => i.to_string(),
+            CellValue::Float(f) => f.to_string(),
+            CellValue::Bool(b) => b.to_string(),
+            CellValue::DateTime(s) => s.clone(),
+            CellValue::String(s) => s.clone(),
+            CellValue::Error(s) => s.clone(),
+            CellValue::Empty => String::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CellData {
     pub column_name: String,
-    pub value: String,
+    pub value: CellValue,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,3 +341,52 @@ pub struct SheetData {
 pub struct ExcelData {
     pub sheets: Vec<SheetData>,
 }
+
+#[cfg(test)]
+mod date_tests {
+    use super::excel_serial_to_iso8601;
+
+    #[test]
+    fn converts_date_serial_to_iso8601() {
+        assert_eq!(excel_serial_to_iso8601(45322.0), "2024-01-31T00:00:00");
+    }
+
+    #[test]
+    fn converts_datetime_serial_with_time_component() {
+        assert_eq!(
+            excel_serial_to_iso8601(45322.0 + (13.0 * 3600.0 + 45.0 * 60.0) / 86400.0),
+            "2024-01-31T13:45:00"
+        );
+    }
+
+    #[test]
+    fn converts_time_only_serial() {
+        assert_eq!(excel_serial_to_iso8601(0.5), "12:00:00");
+    }
+}
+
+#[cfg(test)]
+mod select_indices_tests {
+    use super::select_indices;
+
+    #[test]
+    fn returns_everything_when_no_columns_given() {
+        let items = vec!["ID".to_string(), "Name".to_string(), "Age".to_string()];
+        assert_eq!(select_indices(&items, None), items);
+    }
+
+    #[test]
+    fn projects_onto_the_given_indices_in_order() {
+        let items = vec!["ID".to_string(), "Name".to_string(), "Age".to_string()];
+        assert_eq!(
+            select_indices(&items, Some(&[2, 0])),
+            vec!["Age".to_string(), "ID".to_string()]
+        );
+    }
+
+    #[test]
+    fn skips_out_of_range_indices() {
+        let items = vec!["ID".to_string(), "Name".to_string()];
+        assert_eq!(select_indices(&items, Some(&[0, 5])), vec!["ID".to_string()]);
+    }
+}
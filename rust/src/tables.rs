@@ -0,0 +1,145 @@
+//! Renders a `SheetData` as a GitHub-flavored Markdown table or an AsciiDoc
+//! table, so spreadsheet data can be dropped straight into documentation.
+
+use crate::parser::SheetData;
+use crate::writer::HeaderConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableFormat {
+    Markdown,
+    AsciiDoc,
+}
+
+/// Bundles the sheet to render with the header widths that drive the
+/// AsciiDoc `[cols="..."]` spec.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TableWriteConfig {
+    pub sheet: SheetData,
+    pub headers: Vec<HeaderConfig>,
+}
+
+pub struct TableRenderer;
+
+impl TableRenderer {
+    pub fn render(sheet: &SheetData, headers: &[HeaderConfig], format: TableFormat) -> String {
+        match format {
+            TableFormat::Markdown => render_markdown(sheet),
+            TableFormat::AsciiDoc => render_asciidoc(sheet, headers),
+        }
+    }
+}
+
+fn render_markdown(sheet: &SheetData) -> String {
+    let mut out = String::new();
+    out.push_str(&markdown_row(&sheet.column_names));
+
+    let separator = vec!["---".to_string(); sheet.column_names.len()];
+    out.push_str(&markdown_row(&separator));
+
+    for row in &sheet.rows {
+        let values: Vec<String> = row
+            .cells
+            .iter()
+            .map(|cell| cell.value.as_display_string())
+            .collect();
+        out.push_str(&markdown_row(&values));
+    }
+
+    out
+}
+
+fn markdown_row(values: &[String]) -> String {
+    let escaped: Vec<String> = values.iter().map(|v| v.replace('|', "\\|")).collect();
+    format!("| {} |\n", escaped.join(" | "))
+}
+
+fn render_asciidoc(sheet: &SheetData, headers: &[HeaderConfig]) -> String {
+    let widths = normalize_widths(headers, sheet.column_names.len());
+    let cols_spec = widths
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut out = String::new();
+    out.push_str(&format!("[cols=\"{cols_spec}\", options=\"header\"]\n"));
+    out.push_str("|===\n");
+    out.push_str(&asciidoc_row(&sheet.column_names));
+
+    for row in &sheet.rows {
+        let values: Vec<String> = row
+            .cells
+            .iter()
+            .map(|cell| cell.value.as_display_string())
+            .collect();
+        out.push_str(&asciidoc_row(&values));
+    }
+
+    out.push_str("|===\n");
+    out
+}
+
+fn asciidoc_row(values: &[String]) -> String {
+    let mut row: String = values.iter().map(|v| format!("|{v} ")).collect();
+    row.push('\n');
+    row
+}
+
+/// Normalizes each column's configured `HeaderConfig.width` into an integer
+/// percentage, clamped to a minimum of 1 so the spec never contains a `0`
+/// (AsciiDoc rejects that), with any rounding drift folded into the largest
+/// column so the spec still sums to exactly 100 (with more than 100 columns
+/// the minimum of 1 each puts the sum above 100). A column with no configured
+/// width, or a non-positive one, counts as a width of 1.
+fn normalize_widths(headers: &[HeaderConfig], column_count: usize) -> Vec<u32> {
+    if column_count == 0 {
+        return Vec::new();
+    }
+
+    let raw_widths: Vec<f64> = (0..column_count)
+        .map(|i| {
+            headers
+                .get(i)
+                .and_then(|h| h.width)
+                .filter(|w| *w > 0.0)
+                .unwrap_or(1.0)
+        })
+        .collect();
+
+    let total: f64 = raw_widths.iter().sum();
+    let mut percentages: Vec<i64> = raw_widths
+        .iter()
+        .map(|w| ((w / total) * 100.0).round() as i64)
+        .map(|p| p.max(1))
+        .collect();
+
+    let drift = 100 - percentages.iter().sum::<i64>();
+    apply_drift(&mut percentages, drift);
+
+    percentages.into_iter().map(|p| p.max(1) as u32).collect()
+}
+
+/// Moves `drift` (positive or negative) one unit at a time into whichever
+/// entry is currently largest, so the spec's total stays at 100 without ever
+/// pushing an entry below 1.
+fn apply_drift(percentages: &mut [i64], drift: i64) {
+    let step = if drift >= 0 { 1 } else { -1 };
+    let mut remaining = drift;
+
+    while remaining != 0 {
+        let target = percentages
+            .iter()
+            .enumerate()
+            .filter(|(_, &p)| step > 0 || p > 1)
+            .max_by_key(|(_, &p)| p)
+            .map(|(idx, _)| idx);
+
+        let Some(idx) = target else {
+            break;
+        };
+
+        percentages[idx] += step;
+        remaining -= step;
+    }
+}
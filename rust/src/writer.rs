@@ -1,12 +1,16 @@
+use rust_xlsxwriter::{DataValidation, Format, FormatAlign, Url, Workbook, Worksheet};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
-use rust_xlsxwriter::{Workbook, Format, FormatAlign};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WriteConfig {
     pub sheet_name: String,
     pub headers: Vec<HeaderConfig>,
     pub data: Vec<Vec<String>>,
+    #[serde(default)]
+    pub cell_styles: Vec<CellStyleEntry>,
+    #[serde(default)]
+    pub merged_ranges: Vec<MergeRange>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +18,39 @@ pub struct HeaderConfig {
     pub name: String,
     pub width: Option<f64>,
     pub format: Option<String>,
+    #[serde(default)]
+    pub font_color: Option<String>,
+    #[serde(default)]
+    pub background_color: Option<String>,
+}
+
+/// Styling for a single data cell: a hyperlink, a dropdown data-validation
+/// list, and/or font/background colors (as `"#RRGGBB"` hex strings).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CellStyle {
+    pub hyperlink: Option<String>,
+    pub data_validation_list: Option<Vec<String>>,
+    pub font_color: Option<String>,
+    pub background_color: Option<String>,
+}
+
+/// A `CellStyle` applied at a specific (row, col), addressed relative to the
+/// worksheet (row 0 is the header row).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CellStyleEntry {
+    pub row: u32,
+    pub col: u16,
+    pub style: CellStyle,
+}
+
+/// A merged cell range, written with `rust_xlsxwriter`'s `merge_range`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MergeRange {
+    pub first_row: u32,
+    pub first_col: u16,
+    pub last_row: u32,
+    pub last_col: u16,
+    pub value: String,
 }
 
 pub struct ExcelWriter {
@@ -29,57 +66,119 @@ impl ExcelWriter {
     pub fn write_data(&self, config: &WriteConfig) -> Result<(), Box<dyn Error>> {
         let mut workbook = Workbook::new();
         let worksheet = workbook.add_worksheet();
-        
-        // Set worksheet name
         worksheet.set_name(&config.sheet_name)?;
-        
-        // Create header format
-        let header_format = Format::new()
-            .set_bold()
-            .set_align(FormatAlign::Center)
-            .set_background_color(0xD3D3D3); // Light gray
-        
+
+        self.write_sheet(worksheet, config)?;
+
+        workbook.save(&self.file_path)?;
+        Ok(())
+    }
+
+    /// Write data with multiple sheets
+    pub fn write_multiple_sheets(&self, configs: Vec<WriteConfig>) -> Result<(), Box<dyn Error>> {
+        let mut workbook = Workbook::new();
+
+        for config in &configs {
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_name(&config.sheet_name)?;
+
+            self.write_sheet(worksheet, config)?;
+        }
+
+        workbook.save(&self.file_path)?;
+        Ok(())
+    }
+
+    /// Write headers, data, per-cell styling, and merged ranges for a single
+    /// sheet. Shared by `write_data` and `write_multiple_sheets`.
+    fn write_sheet(
+        &self,
+        worksheet: &mut Worksheet,
+        config: &WriteConfig,
+    ) -> Result<(), Box<dyn Error>> {
+        validate_merge_ranges(&config.merged_ranges)?;
+
+        let mut cell_styles: std::collections::HashMap<(u32, u16), &CellStyle> =
+            std::collections::HashMap::new();
+        for entry in &config.cell_styles {
+            cell_styles.insert((entry.row, entry.col), &entry.style);
+        }
+
         // Write headers
         for (col, header) in config.headers.iter().enumerate() {
+            let mut header_format = Format::new()
+                .set_bold()
+                .set_align(FormatAlign::Center)
+                .set_background_color(0xD3D3D3); // Light gray
+
+            if let Some(color) = &header.font_color {
+                header_format = header_format.set_font_color(parse_hex_color(color)?);
+            }
+            if let Some(color) = &header.background_color {
+                header_format = header_format.set_background_color(parse_hex_color(color)?);
+            }
+
             worksheet.write_with_format(0, col as u16, &header.name, &header_format)?;
-            
-            // Set column width if specified
+
             if let Some(width) = header.width {
                 worksheet.set_column_width(col as u16, width)?;
             }
         }
-        
+
         // Write data rows
         for (row_idx, row_data) in config.data.iter().enumerate() {
+            let row = (row_idx + 1) as u32;
+
             for (col_idx, cell_value) in row_data.iter().enumerate() {
-                // Apply format if specified for this column
-                if let Some(format_str) = &config.headers.get(col_idx).and_then(|h| h.format.as_ref()) {
-                    let cell_format = self.create_format_from_string(format_str)?;
-                    worksheet.write_with_format(
-                        (row_idx + 1) as u32,
-                        col_idx as u16,
-                        cell_value,
-                        &cell_format
-                    )?;
+                let col = col_idx as u16;
+                let style = cell_styles.get(&(row, col));
+                let num_format = config.headers.get(col_idx).and_then(|h| h.format.as_deref());
+
+                let format = match style {
+                    Some(style) => Some(self.build_cell_format(style, num_format)?),
+                    None => num_format
+                        .map(|format_str| self.create_format_from_string(format_str))
+                        .transpose()?,
+                };
+
+                if let Some(hyperlink) = style.and_then(|s| s.hyperlink.as_ref()) {
+                    let url = Url::new(hyperlink.as_str()).set_text(cell_value);
+                    match &format {
+                        Some(format) => worksheet.write_url_with_format(row, col, &url, format)?,
+                        None => worksheet.write_url(row, col, &url)?,
+                    };
+                } else if let Some(format) = &format {
+                    worksheet.write_with_format(row, col, cell_value, format)?;
                 } else {
-                    worksheet.write(
-                        (row_idx + 1) as u32,
-                        col_idx as u16,
-                        cell_value
-                    )?;
+                    worksheet.write(row, col, cell_value)?;
+                }
+
+                if let Some(list) = style.and_then(|s| s.data_validation_list.as_ref()) {
+                    let choices: Vec<&str> = list.iter().map(String::as_str).collect();
+                    let validation = DataValidation::new().allow_list_strings(&choices)?;
+                    worksheet.add_data_validation(row, col, row, col, &validation)?;
                 }
             }
         }
-        
-        // Save the workbook
-        workbook.save(&self.file_path)?;
+
+        for range in &config.merged_ranges {
+            worksheet.merge_range(
+                range.first_row,
+                range.first_col,
+                range.last_row,
+                range.last_col,
+                &range.value,
+                &Format::new(),
+            )?;
+        }
+
         Ok(())
     }
-    
+
     /// Create format from format string
     fn create_format_from_string(&self, format_str: &str) -> Result<Format, Box<dyn Error>> {
         let mut format = Format::new();
-        
+
         match format_str {
             "@" => {
                 // Text format
@@ -106,46 +205,63 @@ impl ExcelWriter {
                 format = format.set_num_format(format_str);
             }
         }
-        
+
         Ok(format)
     }
 
-    /// Write data with multiple sheets
-    pub fn write_multiple_sheets(&self, configs: Vec<WriteConfig>) -> Result<(), Box<dyn Error>> {
-        let mut workbook = Workbook::new();
-        
-        for config in configs {
-            let worksheet = workbook.add_worksheet();
-            worksheet.set_name(&config.sheet_name)?;
-            
-            // Create header format
-            let header_format = Format::new()
-                .set_bold()
-                .set_align(FormatAlign::Center)
-                .set_background_color(0xD3D3D3);
-            
-            // Write headers
-            for (col, header) in config.headers.iter().enumerate() {
-                worksheet.write_with_format(0, col as u16, &header.name, &header_format)?;
-                
-                if let Some(width) = header.width {
-                    worksheet.set_column_width(col as u16, width)?;
-                }
-            }
-            
-            // Write data
-            for (row_idx, row_data) in config.data.iter().enumerate() {
-                for (col_idx, cell_value) in row_data.iter().enumerate() {
-                    worksheet.write(
-                        (row_idx + 1) as u32,
-                        col_idx as u16,
-                        cell_value
-                    )?;
-                }
+    /// Build a `Format` from a per-cell `CellStyle`'s font/background colors,
+    /// layered on top of the column's num-format so styling a cell doesn't
+    /// clobber its number/date formatting.
+    fn build_cell_format(
+        &self,
+        style: &CellStyle,
+        num_format: Option<&str>,
+    ) -> Result<Format, Box<dyn Error>> {
+        let mut format = match num_format {
+            Some(format_str) => self.create_format_from_string(format_str)?,
+            None => Format::new(),
+        };
+
+        if let Some(color) = &style.font_color {
+            format = format.set_font_color(parse_hex_color(color)?);
+        }
+        if let Some(color) = &style.background_color {
+            format = format.set_background_color(parse_hex_color(color)?);
+        }
+
+        Ok(format)
+    }
+}
+
+/// Parses a `"#RRGGBB"` (or `"RRGGBB"`) hex color string into the `u32` form
+/// `rust_xlsxwriter`'s color setters expect.
+fn parse_hex_color(hex: &str) -> Result<u32, Box<dyn Error>> {
+    let hex = hex.trim_start_matches('#');
+    u32::from_str_radix(hex, 16).map_err(|e| format!("invalid color \"{hex}\": {e}").into())
+}
+
+fn validate_merge_ranges(ranges: &[MergeRange]) -> Result<(), Box<dyn Error>> {
+    for (i, a) in ranges.iter().enumerate() {
+        for b in &ranges[i + 1..] {
+            let rows_overlap = a.first_row <= b.last_row && b.first_row <= a.last_row;
+            let cols_overlap = a.first_col <= b.last_col && b.first_col <= a.last_col;
+
+            if rows_overlap && cols_overlap {
+                return Err(format!(
+                    "merged ranges overlap: ({}, {})-({}, {}) and ({}, {})-({}, {})",
+                    a.first_row,
+                    a.first_col,
+                    a.last_row,
+                    a.last_col,
+                    b.first_row,
+                    b.first_col,
+                    b.last_row,
+                    b.last_col
+                )
+                .into());
             }
         }
-        
-        workbook.save(&self.file_path)?;
-        Ok(())
     }
-}
\ No newline at end of file
+
+    Ok(())
+}